@@ -0,0 +1,134 @@
+//! Batch Ed25519 signature verification
+//!
+//! Verifying many independent signatures individually is several times
+//! slower than checking them all at once via a single randomized batch
+//! equation. See [RFC 8032] / the `ed25519-dalek` `batch` feature for
+//! background on the technique used here.
+//!
+//! [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032
+//!
+//! Gated behind this crate's own `batch` Cargo feature (see the `#[cfg]`
+//! on the `batch` module declaration in `lib.rs`). That feature must
+//! also forward to `ed25519-dalek`'s `batch` feature in `Cargo.toml`
+//! (`batch = ["ed25519-dalek/batch"]`), since [`ed25519_dalek::verify_batch`]
+//! only exists when it's enabled there.
+
+use crate::{public_key::PublicKey, signature::Signature, Error};
+
+/// Accumulates `(public key, message, signature)` triples and verifies
+/// them all in a single batched operation.
+///
+/// A failed batch does **not** identify which signature was invalid -
+/// callers that need to know which signature failed should fall back to
+/// verifying each entry individually with [`PublicKey::verify`].
+#[derive(Default)]
+pub struct BatchVerifier<'a> {
+    public_keys: Vec<&'a PublicKey>,
+    messages: Vec<&'a [u8]>,
+    signatures: Vec<Signature>,
+}
+
+impl<'a> BatchVerifier<'a> {
+    /// Create a new, empty batch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a `(public key, message, signature)` triple for verification
+    pub fn queue(&mut self, public_key: &'a PublicKey, msg: &'a [u8], signature: Signature) {
+        self.public_keys.push(public_key);
+        self.messages.push(msg);
+        self.signatures.push(signature);
+    }
+
+    /// Number of signatures queued in this batch
+    pub fn len(&self) -> usize {
+        self.signatures.len()
+    }
+
+    /// Is this batch empty?
+    pub fn is_empty(&self) -> bool {
+        self.signatures.is_empty()
+    }
+
+    /// Verify every signature in the batch.
+    ///
+    /// Draws a random 128-bit scalar `z_i` per entry and checks the
+    /// combined equation
+    /// `[sum(z_i * s_i)] * B = sum(z_i * R_i) + sum(z_i * H(R_i || A_i || M_i) * A_i)`,
+    /// which holds with overwhelming probability only if every individual
+    /// signature is valid.
+    pub fn verify(self) -> Result<(), Error> {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        let public_keys = self
+            .public_keys
+            .iter()
+            .map(|pk| {
+                pk.ed25519()
+                    .cloned()
+                    .ok_or_else(|| Error::invalid_key("not an Ed25519 public key"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let signatures = self
+            .signatures
+            .iter()
+            .map(|sig| {
+                ed25519_dalek::Signature::from_bytes(sig.as_bytes())
+                    .map_err(|_| Error::invalid_signature("malformed Ed25519 signature"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ed25519_dalek::verify_batch(&self.messages, &signatures, &public_keys)
+            .map_err(|_| Error::invalid_signature("batch signature verification failed"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::private_key::Ed25519Keypair;
+
+    #[test]
+    fn verifies_a_batch_of_valid_signatures() {
+        let keypairs: Vec<_> = (0..3).map(|_| Ed25519Keypair::generate_with_os_rng()).collect();
+        let messages: Vec<&[u8]> = vec![b"alpha", b"beta", b"gamma"];
+        let public_keys: Vec<_> = keypairs.iter().map(|kp| kp.public_key()).collect();
+
+        let mut batch = BatchVerifier::new();
+        for ((keypair, public_key), msg) in keypairs.iter().zip(&public_keys).zip(&messages) {
+            batch.queue(public_key, msg, keypair.sign(msg));
+        }
+
+        assert!(batch.verify().is_ok());
+    }
+
+    #[test]
+    fn rejects_a_batch_with_a_tampered_entry() {
+        let keypairs: Vec<_> = (0..3).map(|_| Ed25519Keypair::generate_with_os_rng()).collect();
+        let messages: [&[u8]; 3] = [b"alpha", b"beta", b"gamma"];
+        let public_keys: Vec<_> = keypairs.iter().map(|kp| kp.public_key()).collect();
+        let signatures: Vec<_> = keypairs
+            .iter()
+            .zip(&messages)
+            .map(|(kp, msg)| kp.sign(msg))
+            .collect();
+
+        let mut batch = BatchVerifier::new();
+        for (i, ((public_key, msg), signature)) in public_keys
+            .iter()
+            .zip(messages)
+            .zip(signatures)
+            .enumerate()
+        {
+            // Verify the second entry's signature against the wrong message.
+            let msg: &[u8] = if i == 1 { b"tampered" } else { msg };
+            batch.queue(public_key, msg, signature);
+        }
+
+        assert!(batch.verify().is_err());
+    }
+}
@@ -0,0 +1,90 @@
+//! BIP32-style derivation paths
+//!
+//! Used to derive validator keys deterministically from a single seed,
+//! matching the `StandardHDPath` convention used by tools like Hermes'
+//! keyring (e.g. `m/44'/118'/0'/0'/0'` for a Cosmos validator key).
+
+use std::{fmt, str::FromStr};
+
+use crate::Error;
+
+/// A single component of a [`DerivationPath`], e.g. `44'` or `0`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ChildNumber {
+    index: u32,
+    hardened: bool,
+}
+
+impl ChildNumber {
+    /// The raw index, with the hardened bit folded in (as used by
+    /// BIP32/SLIP-0010 derivation)
+    pub fn to_bits(self) -> u32 {
+        if self.hardened {
+            self.index | (1 << 31)
+        } else {
+            self.index
+        }
+    }
+
+    /// Is this a hardened child index?
+    pub fn is_hardened(self) -> bool {
+        self.hardened
+    }
+}
+
+/// A BIP32 derivation path, e.g. `m/44'/118'/0'/0'/0'`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DerivationPath {
+    children: Vec<ChildNumber>,
+}
+
+impl DerivationPath {
+    /// The path's components, in derivation order
+    pub fn iter(&self) -> impl Iterator<Item = ChildNumber> + '_ {
+        self.children.iter().copied()
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut components = s.split('/');
+
+        if components.next() != Some("m") {
+            return Err(Error::invalid_key("derivation path must start with \"m\""));
+        }
+
+        let children = components
+            .map(|component| {
+                let (index, hardened) = match component.strip_suffix('\'') {
+                    Some(index) => (index, true),
+                    None => (component, false),
+                };
+
+                index
+                    .parse::<u32>()
+                    .map(|index| ChildNumber { index, hardened })
+                    .map_err(|_| Error::invalid_key("invalid derivation path component"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(DerivationPath { children })
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+
+        for child in &self.children {
+            write!(f, "/{}", child.index)?;
+
+            if child.hardened {
+                f.write_str("'")?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+//! Tendermint data structures and cryptographic primitives
+
+#[cfg(feature = "batch")]
+pub mod batch;
+pub mod derivation;
+pub mod private_key;